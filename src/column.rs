@@ -0,0 +1,95 @@
+use crate::{ExcessHandler, ExcessHandlingFunction, PadDirection, PaddedItem, Width};
+use core::fmt::Display;
+
+#[cfg(feature = "std")]
+use derive_builder::Builder;
+
+/// Pad a whole column of values, deriving `total_width` from the widest value instead of
+/// making the caller compute it by hand.
+///
+/// **Key methods:**
+/// * [`total_width`](Self::total_width): The width every value in the column is padded to.
+/// * [`items`](Self::items): Iterator of [`PaddedItem`], one per value, all sharing that width.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use padded_column::{PaddedColumn, PadDirection, ForbidExcess};
+/// let column = PaddedColumn {
+///     values: &["a", "bb", "ccc"],
+///     pad_block: '-',
+///     pad_direction: PadDirection::Left,
+///     handle_excess: ForbidExcess,
+/// };
+/// let rendered: Vec<_> = column.items().map(|item| item.to_string()).collect();
+/// assert_eq!(rendered, ["--a", "-bb", "ccc"]);
+/// ```
+///
+/// **Example:** Use a [builder](PaddedColumnBuilder) _(requires `std` feature)_
+///
+/// ```
+/// # #[cfg(feature = "std")] fn main() {
+/// # use pretty_assertions::assert_eq;
+/// use padded_column::{PaddedColumnBuilder, PadDirection, ForbidExcess};
+/// let column = PaddedColumnBuilder::default()
+///     .values(&["a", "bb", "ccc"][..])
+///     .pad_block('-')
+///     .pad_direction(PadDirection::Left)
+///     .handle_excess(ForbidExcess)
+///     .build()
+///     .unwrap();
+/// let rendered: Vec<_> = column.items().map(|item| item.to_string()).collect();
+/// assert_eq!(rendered, ["--a", "-bb", "ccc"]);
+/// # }
+/// # #[cfg(not(feature = "std"))] fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Builder))]
+pub struct PaddedColumn<
+    'v,
+    Value,
+    PadBlock = char,
+    HandleExcess = ExcessHandlingFunction<Value, PadBlock>,
+> where
+    Value: Width + Clone + Display,
+    PadBlock: Display + Clone,
+    HandleExcess: ExcessHandler<Value, PadBlock> + Clone,
+{
+    /// Values to be padded, all to the same width.
+    pub values: &'v [Value],
+    /// Block of the pad (expected to have width of 1).
+    pub pad_block: PadBlock,
+    /// Where to place the pad.
+    pub pad_direction: PadDirection,
+    /// How to write a value whose width exceeds the column's derived `total_width`.
+    pub handle_excess: HandleExcess,
+}
+
+impl<'v, Value, PadBlock, HandleExcess> PaddedColumn<'v, Value, PadBlock, HandleExcess>
+where
+    Value: Width + Clone + Display,
+    PadBlock: Display + Clone,
+    HandleExcess: ExcessHandler<Value, PadBlock> + Clone,
+{
+    /// The width of the widest value in [`values`](Self::values).
+    ///
+    /// Every [`PaddedItem`] produced by [`items`](Self::items) is padded to this width.
+    pub fn total_width(&self) -> usize {
+        self.values.iter().map(Width::width).max().unwrap_or(0)
+    }
+
+    /// One [`PaddedItem`] per value in [`values`](Self::values), all padded to
+    /// [`total_width`](Self::total_width).
+    pub fn items(&self) -> impl Iterator<Item = PaddedItem<Value, PadBlock, HandleExcess>> + '_ {
+        let total_width = self.total_width();
+        let pad_direction = self.pad_direction;
+        self.values.iter().cloned().map(move |value| PaddedItem {
+            value,
+            pad_block: self.pad_block.clone(),
+            total_width,
+            pad_direction,
+            handle_excess: self.handle_excess.clone(),
+        })
+    }
+}
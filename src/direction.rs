@@ -0,0 +1,30 @@
+/// Where to place the pad relative to the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PadDirection {
+    /// Pad before the value, e.g. `"---abc"`.
+    Left,
+    /// Pad after the value, e.g. `"abc---"`.
+    Right,
+    /// Pad on both sides of the value, e.g. `"-abc--"`.
+    ///
+    /// When `total_width - value_width` is odd, the leftover block goes to the side
+    /// indicated by the accompanying [`CenterBias`].
+    Center(CenterBias),
+}
+
+/// Which side receives the extra pad block when [`PadDirection::Center`] can't split the
+/// leftover width evenly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CenterBias {
+    /// Give the extra block to the left side.
+    Left,
+    /// Give the extra block to the right side.
+    Right,
+}
+
+impl Default for CenterBias {
+    /// Defaults to [`CenterBias::Right`], placing the extra block after the value.
+    fn default() -> Self {
+        CenterBias::Right
+    }
+}
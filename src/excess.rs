@@ -0,0 +1,107 @@
+use crate::Width;
+use core::fmt::{Display, Error, Write};
+
+/// Information about a value whose width exceeds the column's `total_width`.
+pub struct Excess<'a, Value, PadBlock>
+where
+    Value: Width,
+    PadBlock: Display,
+{
+    /// The value that overflowed.
+    pub value: &'a Value,
+    /// The actual width of `value`.
+    pub value_width: usize,
+    /// The width the column was supposed to fulfill.
+    pub total_width: usize,
+    /// The pad block that would have been used, had there been room for padding.
+    pub pad_block: &'a PadBlock,
+}
+
+/// Decide what to write when a value's width exceeds `total_width`.
+pub trait ExcessHandler<Value, PadBlock>
+where
+    Value: Width,
+    PadBlock: Display,
+{
+    /// Write the excess to `out`.
+    fn handle_excess<W: Write>(
+        &self,
+        excess: Excess<'_, Value, PadBlock>,
+        out: &mut W,
+    ) -> Result<(), Error>;
+}
+
+/// Refuse to format an overflowing value, returning a formatting error instead.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ForbidExcess;
+
+impl<Value, PadBlock> ExcessHandler<Value, PadBlock> for ForbidExcess
+where
+    Value: Width,
+    PadBlock: Display,
+{
+    fn handle_excess<W: Write>(
+        &self,
+        _excess: Excess<'_, Value, PadBlock>,
+        _out: &mut W,
+    ) -> Result<(), Error> {
+        Err(Error)
+    }
+}
+
+/// Write the value as-is, ignoring that it doesn't fit `total_width`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WriteExcess;
+
+impl<Value, PadBlock> ExcessHandler<Value, PadBlock> for WriteExcess
+where
+    Value: Width + Display,
+    PadBlock: Display,
+{
+    fn handle_excess<W: Write>(
+        &self,
+        excess: Excess<'_, Value, PadBlock>,
+        out: &mut W,
+    ) -> Result<(), Error> {
+        write!(out, "{}", excess.value)
+    }
+}
+
+/// A plain function pointer suitable for wrapping in an [`ExcessHandlingFunction`].
+///
+/// It takes `out` as `&mut dyn Write` (rather than a generic parameter) so it can stay a
+/// plain function pointer rather than a generic one.
+type ExcessHandlingFn<Value, PadBlock> =
+    fn(Excess<'_, Value, PadBlock>, &mut dyn Write) -> Result<(), Error>;
+
+/// Adapt a plain function into an [`ExcessHandler`].
+#[derive(Clone, Copy)]
+pub struct ExcessHandlingFunction<Value, PadBlock>(ExcessHandlingFn<Value, PadBlock>)
+where
+    Value: Width,
+    PadBlock: Display;
+
+impl<Value, PadBlock> ExcessHandlingFunction<Value, PadBlock>
+where
+    Value: Width,
+    PadBlock: Display,
+{
+    /// Wrap `function` as an [`ExcessHandler`].
+    pub fn new(function: ExcessHandlingFn<Value, PadBlock>) -> Self {
+        ExcessHandlingFunction(function)
+    }
+}
+
+impl<Value, PadBlock> ExcessHandler<Value, PadBlock> for ExcessHandlingFunction<Value, PadBlock>
+where
+    Value: Width,
+    PadBlock: Display,
+{
+    fn handle_excess<W: Write>(
+        &self,
+        excess: Excess<'_, Value, PadBlock>,
+        out: &mut W,
+    ) -> Result<(), Error> {
+        (self.0)(excess, out)
+    }
+}
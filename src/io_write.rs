@@ -0,0 +1,41 @@
+use core::fmt;
+use std::io;
+
+/// Bridges a [`core::fmt::Write`] call onto an [`io::Write`] sink, so `write_to_io` methods
+/// can reuse the exact same zero-allocation formatting as `write_to` without duplicating it.
+pub(crate) struct IoWriteAdapter<'a, W> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<'a, W: io::Write> IoWriteAdapter<'a, W> {
+    pub(crate) fn new(inner: &'a mut W) -> Self {
+        IoWriteAdapter {
+            inner,
+            error: None,
+        }
+    }
+
+    /// Turn the result of a `fmt::Write`-based format into an [`io::Result`], translating a
+    /// [`fmt::Error`] back into the [`io::Error`] that caused it whenever possible.
+    pub(crate) fn finish(self, result: fmt::Result) -> io::Result<()> {
+        match result {
+            Ok(()) => Ok(()),
+            Err(_) => Err(self
+                .error
+                .unwrap_or_else(|| io::Error::other("formatting error"))),
+        }
+    }
+}
+
+impl<W: io::Write> fmt::Write for IoWriteAdapter<'_, W> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        match self.inner.write_all(text.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(error) => {
+                self.error = Some(error);
+                Err(fmt::Error)
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use crate::{Excess, ExcessHandler, ExcessHandlingFunction, PadDirection, Width};
-use core::fmt::{Display, Error, Formatter};
+use crate::{CenterBias, Excess, ExcessHandler, ExcessHandlingFunction, PadDirection, Width};
+use core::fmt::{self, Display, Error, Formatter};
 
 #[cfg(feature = "std")]
 use derive_builder::Builder;
@@ -24,6 +24,51 @@ use derive_builder::Builder;
 /// assert_eq!(padded_item.to_string(), "---abcdef");
 /// ```
 ///
+/// **Example:** Center a string, with the extra block going to the right on odd leftover width
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use padded_column::{PaddedItem, PadDirection, CenterBias, ForbidExcess};
+/// let padded_item = PaddedItem {
+///     value: "abc",
+///     pad_block: '_',
+///     total_width: 8,
+///     pad_direction: PadDirection::Center(CenterBias::Right),
+///     handle_excess: ForbidExcess,
+/// };
+/// assert_eq!(padded_item.to_string(), "__abc___");
+/// ```
+///
+/// **Example:** Same leftover width, but biased to the left instead
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use padded_column::{PaddedItem, PadDirection, CenterBias, ForbidExcess};
+/// let padded_item = PaddedItem {
+///     value: "abc",
+///     pad_block: '_',
+///     total_width: 8,
+///     pad_direction: PadDirection::Center(CenterBias::Left),
+///     handle_excess: ForbidExcess,
+/// };
+/// assert_eq!(padded_item.to_string(), "___abc__");
+/// ```
+///
+/// **Example:** Centering with an even leftover width ignores the bias
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use padded_column::{PaddedItem, PadDirection, CenterBias, ForbidExcess};
+/// let padded_item = PaddedItem {
+///     value: "abcd",
+///     pad_block: '_',
+///     total_width: 8,
+///     pad_direction: PadDirection::Center(CenterBias::Left),
+///     handle_excess: ForbidExcess,
+/// };
+/// assert_eq!(padded_item.to_string(), "__abcd__");
+/// ```
+///
 /// **Example:** Use a [builder](PaddedItemBuilder) _(requires `std` feature)_
 ///
 /// ```
@@ -49,7 +94,7 @@ pub struct PaddedItem<
     PadBlock = char,
     HandleExcess = ExcessHandlingFunction<Value, PadBlock>,
 > where
-    Value: Width,
+    Value: Width + Display,
     PadBlock: Display,
     HandleExcess: ExcessHandler<Value, PadBlock>,
 {
@@ -65,13 +110,31 @@ pub struct PaddedItem<
     pub handle_excess: HandleExcess,
 }
 
-impl<Value, PadBlock, HandleExcess> Display for PaddedItem<Value, PadBlock, HandleExcess>
+impl<Value, PadBlock, HandleExcess> PaddedItem<Value, PadBlock, HandleExcess>
 where
-    Value: Width,
+    Value: Width + Display,
     PadBlock: Display,
     HandleExcess: ExcessHandler<Value, PadBlock>,
 {
-    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+    /// Write the padded value into `out`, without allocating an intermediate [`String`].
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use padded_column::{PaddedItem, PadDirection, ForbidExcess};
+    /// let padded_item = PaddedItem {
+    ///     value: "abcdef",
+    ///     pad_block: '-',
+    ///     total_width: 9,
+    ///     pad_direction: PadDirection::Left,
+    ///     handle_excess: ForbidExcess,
+    /// };
+    /// let mut buffer = String::from("prefix: ");
+    /// padded_item.write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, "prefix: ---abcdef");
+    /// ```
+    pub fn write_to<W: fmt::Write>(&self, out: &mut W) -> Result<(), Error> {
         let PaddedItem {
             value,
             pad_block,
@@ -91,13 +154,67 @@ where
                     total_width,
                     pad_block,
                 },
-                formatter,
+                out,
             );
         };
-        let pad = fmt_iter::repeat(pad_block, pad_width);
         match *pad_direction {
-            PadDirection::Left => write!(formatter, "{}{}", pad, value),
-            PadDirection::Right => write!(formatter, "{}{}", value, pad),
+            PadDirection::Left => {
+                let pad = fmt_iter::repeat(pad_block, pad_width);
+                write!(out, "{}{}", pad, value)
+            }
+            PadDirection::Right => {
+                let pad = fmt_iter::repeat(pad_block, pad_width);
+                write!(out, "{}{}", value, pad)
+            }
+            PadDirection::Center(bias) => {
+                let left = match bias {
+                    CenterBias::Left => pad_width - pad_width / 2,
+                    CenterBias::Right => pad_width / 2,
+                };
+                let right = pad_width - left;
+                let left_pad = fmt_iter::repeat(pad_block, left);
+                let right_pad = fmt_iter::repeat(pad_block, right);
+                write!(out, "{}{}{}", left_pad, value, right_pad)
+            }
         }
     }
+
+    /// Write the padded value into an [`io::Write`](std::io::Write) sink, without allocating
+    /// an intermediate [`String`].
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] fn main() {
+    /// use padded_column::{PaddedItem, PadDirection, ForbidExcess};
+    /// let padded_item = PaddedItem {
+    ///     value: "abcdef",
+    ///     pad_block: '-',
+    ///     total_width: 9,
+    ///     pad_direction: PadDirection::Left,
+    ///     handle_excess: ForbidExcess,
+    /// };
+    /// let mut buffer = Vec::new();
+    /// padded_item.write_to_io(&mut buffer).unwrap();
+    /// assert_eq!(buffer, b"---abcdef");
+    /// # }
+    /// # #[cfg(not(feature = "std"))] fn main() {}
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to_io<W: std::io::Write>(&self, out: &mut W) -> std::io::Result<()> {
+        let mut adapter = crate::io_write::IoWriteAdapter::new(out);
+        let result = self.write_to(&mut adapter);
+        adapter.finish(result)
+    }
+}
+
+impl<Value, PadBlock, HandleExcess> Display for PaddedItem<Value, PadBlock, HandleExcess>
+where
+    Value: Width + Display,
+    PadBlock: Display,
+    HandleExcess: ExcessHandler<Value, PadBlock>,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        self.write_to(formatter)
+    }
 }
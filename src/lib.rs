@@ -0,0 +1,22 @@
+//! Pad a single value, or a whole column of values, to a fixed width.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod column;
+mod direction;
+mod excess;
+#[cfg(feature = "std")]
+mod io_write;
+mod item;
+mod truncate;
+mod unpad;
+mod value;
+mod width;
+
+pub use column::*;
+pub use direction::*;
+pub use excess::*;
+pub use item::*;
+pub use truncate::*;
+pub use unpad::*;
+pub use value::*;
+pub use width::*;
@@ -0,0 +1,149 @@
+use crate::{Excess, ExcessHandler, Width};
+use core::fmt::{Display, Error, Write};
+
+/// Which side of an overflowing value [`TruncateExcess`] cuts from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TruncateDirection {
+    /// Cut off the tail, keeping the value's prefix. Pairs with [`PadDirection::Right`](crate::PadDirection::Right).
+    #[default]
+    Right,
+    /// Cut off the head, keeping the value's suffix. Pairs with [`PadDirection::Left`](crate::PadDirection::Left).
+    Left,
+}
+
+/// Slice an overflowing value down to `total_width` instead of letting it spill past the
+/// column, so tabular output stays aligned.
+///
+/// Truncation is width-aware: it walks the value one character at a time, summing each
+/// character's [`Width`], and stops before exceeding the budget, so a character is never
+/// split. Grapheme clusters made of several characters are not kept together and may be cut.
+///
+/// **Example:** Truncate a too-wide string, keeping its prefix
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use padded_column::{PaddedValue, PadDirection, TruncateExcess, TruncateDirection};
+/// let padded_value = PaddedValue {
+///     value: "abcdefgh",
+///     pad_block: '-',
+///     total_width: 5,
+///     pad_direction: PadDirection::Right,
+///     handle_excess: TruncateExcess {
+///         truncate_direction: TruncateDirection::Right,
+///         ellipsis: None::<char>,
+///     },
+/// };
+/// assert_eq!(padded_value.to_string(), "abcde");
+/// ```
+///
+/// **Example:** Reserve room for an ellipsis and truncate from the left to match a
+/// left-padded column
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use padded_column::{PaddedValue, PadDirection, TruncateExcess, TruncateDirection};
+/// let padded_value = PaddedValue {
+///     value: "abcdefgh",
+///     pad_block: '-',
+///     total_width: 5,
+///     pad_direction: PadDirection::Left,
+///     handle_excess: TruncateExcess {
+///         truncate_direction: TruncateDirection::Left,
+///         ellipsis: Some('…'),
+///     },
+/// };
+/// assert_eq!(padded_value.to_string(), "…efgh");
+/// ```
+///
+/// **Example:** When the ellipsis itself doesn't fit `total_width`, it's dropped rather than
+/// overflowing the column
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use padded_column::{PaddedValue, PadDirection, TruncateExcess, TruncateDirection};
+/// let padded_value = PaddedValue {
+///     value: "abcdefgh",
+///     pad_block: '-',
+///     total_width: 0,
+///     pad_direction: PadDirection::Right,
+///     handle_excess: TruncateExcess {
+///         truncate_direction: TruncateDirection::Right,
+///         ellipsis: Some('…'),
+///     },
+/// };
+/// assert_eq!(padded_value.to_string(), "");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TruncateExcess<Ellipsis = char> {
+    /// Which side of the value to cut off.
+    pub truncate_direction: TruncateDirection,
+    /// Marker written in place of the removed blocks, e.g. `'…'`.
+    pub ellipsis: Option<Ellipsis>,
+}
+
+impl<Ellipsis> Default for TruncateExcess<Ellipsis> {
+    fn default() -> Self {
+        TruncateExcess {
+            truncate_direction: TruncateDirection::default(),
+            ellipsis: None,
+        }
+    }
+}
+
+impl<Value, PadBlock, Ellipsis> ExcessHandler<Value, PadBlock> for TruncateExcess<Ellipsis>
+where
+    Value: Width + AsRef<str>,
+    PadBlock: Display,
+    Ellipsis: Width + Display,
+{
+    fn handle_excess<W: Write>(
+        &self,
+        excess: Excess<'_, Value, PadBlock>,
+        out: &mut W,
+    ) -> Result<(), Error> {
+        let text = excess.value.as_ref();
+        let total_width = excess.total_width;
+        let ellipsis_width = self.ellipsis.as_ref().map(Width::width).unwrap_or(0);
+        let ellipsis_fits = ellipsis_width <= total_width;
+        let budget = total_width.saturating_sub(ellipsis_width);
+
+        let kept = match self.truncate_direction {
+            TruncateDirection::Right => {
+                let mut width = 0;
+                let mut end = 0;
+                for (index, character) in text.char_indices() {
+                    let character_width = character.width();
+                    if width + character_width > budget {
+                        break;
+                    }
+                    width += character_width;
+                    end = index + character.len_utf8();
+                }
+                &text[..end]
+            }
+            TruncateDirection::Left => {
+                let mut width = 0;
+                let mut start = text.len();
+                for (index, character) in text.char_indices().rev() {
+                    let character_width = character.width();
+                    if width + character_width > budget {
+                        break;
+                    }
+                    width += character_width;
+                    start = index;
+                }
+                &text[start..]
+            }
+        };
+
+        match (&self.ellipsis, self.truncate_direction) {
+            (Some(ellipsis), TruncateDirection::Right) if ellipsis_fits => {
+                write!(out, "{}{}", kept, ellipsis)
+            }
+            (Some(ellipsis), TruncateDirection::Left) if ellipsis_fits => {
+                write!(out, "{}{}", ellipsis, kept)
+            }
+            _ => write!(out, "{}", kept),
+        }
+    }
+}
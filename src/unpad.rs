@@ -0,0 +1,103 @@
+use crate::PadDirection;
+
+/// Reasons [`Unpad::unpad`] can fail to recover the original value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnpadError {
+    /// `pad_block` is empty, so there's no unit of padding to measure against.
+    TooShort,
+    /// Stripping consumed the string down to nothing. This is ambiguous: the original value
+    /// may genuinely have been empty, or it may have consisted entirely of `pad_block`
+    /// itself and been indistinguishable from padding.
+    AmbiguousContent,
+}
+
+/// The inverse of padding: recover the original value from a string padded with
+/// [`PaddedValue`](crate::PaddedValue) or [`PaddedItem`](crate::PaddedItem).
+///
+/// Mirrors [`PadDirection`]: removes leading blocks for [`PadDirection::Left`], trailing
+/// blocks for [`PadDirection::Right`], and blocks from both sides for
+/// [`PadDirection::Center`]. `pad_block` is matched as a whole unit (its string form), so a
+/// multi-byte pad block is never partially stripped.
+///
+/// **Limitation:** stripping is greedy — every repeated occurrence of `pad_block` at the
+/// relevant boundary is removed, including ones that are part of the original value rather
+/// than padding. If a value happens to begin (for `Left`/`Center`) or end (for
+/// `Right`/`Center`) with `pad_block` itself, those leading/trailing copies are
+/// indistinguishable from padding and will be stripped too. Recovering the value exactly in
+/// that case requires knowing its width ahead of time, which this trait does not take as
+/// input.
+pub trait Unpad {
+    /// Strip `pad_block` off `self` according to `pad_direction`.
+    ///
+    /// **Example:** Undo left padding
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use padded_column::{PadDirection, Unpad};
+    /// assert_eq!("---abcdef".unpad("-", PadDirection::Left), Ok("abcdef"));
+    /// ```
+    ///
+    /// **Example:** Undo right padding
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use padded_column::{PadDirection, Unpad};
+    /// assert_eq!("abcdef---".unpad("-", PadDirection::Right), Ok("abcdef"));
+    /// ```
+    ///
+    /// **Example:** Undo centered padding, stripping both sides
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use padded_column::{PadDirection, CenterBias, Unpad};
+    /// assert_eq!(
+    ///     "__abc___".unpad("_", PadDirection::Center(CenterBias::Right)),
+    ///     Ok("abc"),
+    /// );
+    /// ```
+    ///
+    /// **Example:** A value made entirely of the pad block is indistinguishable from padding
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use padded_column::{PadDirection, Unpad, UnpadError};
+    /// assert_eq!(
+    ///     "------".unpad("-", PadDirection::Left),
+    ///     Err(UnpadError::AmbiguousContent),
+    /// );
+    /// ```
+    fn unpad(&self, pad_block: &str, pad_direction: PadDirection) -> Result<&str, UnpadError>;
+}
+
+impl Unpad for str {
+    fn unpad(&self, pad_block: &str, pad_direction: PadDirection) -> Result<&str, UnpadError> {
+        if pad_block.is_empty() {
+            return Err(UnpadError::TooShort);
+        }
+        let unpadded = match pad_direction {
+            PadDirection::Left => strip_leading_blocks(self, pad_block),
+            PadDirection::Right => strip_trailing_blocks(self, pad_block),
+            PadDirection::Center(_) => {
+                strip_trailing_blocks(strip_leading_blocks(self, pad_block), pad_block)
+            }
+        };
+        if unpadded.is_empty() && !self.is_empty() {
+            return Err(UnpadError::AmbiguousContent);
+        }
+        Ok(unpadded)
+    }
+}
+
+fn strip_leading_blocks<'a>(mut text: &'a str, pad_block: &str) -> &'a str {
+    while let Some(rest) = text.strip_prefix(pad_block) {
+        text = rest;
+    }
+    text
+}
+
+fn strip_trailing_blocks<'a>(mut text: &'a str, pad_block: &str) -> &'a str {
+    while let Some(rest) = text.strip_suffix(pad_block) {
+        text = rest;
+    }
+    text
+}
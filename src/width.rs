@@ -0,0 +1,33 @@
+/// Types whose displayed width can be measured in terms of "blocks" (typically characters).
+pub trait Width {
+    /// Number of blocks this value occupies when displayed.
+    fn width(&self) -> usize;
+}
+
+impl Width for str {
+    fn width(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl<T> Width for &T
+where
+    T: Width + ?Sized,
+{
+    fn width(&self) -> usize {
+        (**self).width()
+    }
+}
+
+impl Width for char {
+    fn width(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(feature = "std")]
+impl Width for String {
+    fn width(&self) -> usize {
+        self.as_str().width()
+    }
+}